@@ -0,0 +1,182 @@
+//! Import Avro record schemas and map them onto `Schema`/`Field`.
+//!
+//! Only the subset of the Avro spec needed to describe a flat database
+//! table is supported: a top-level `record` whose `fields` are primitives,
+//! logical types, or a `["null", T]` union marking a nullable column.
+
+use std::fmt;
+
+use serde_json::Value;
+
+use crate::field::{FieldOptions, FieldType};
+use crate::schema::Schema;
+
+/// Errors produced while mapping an Avro schema onto a `Schema`.
+#[derive(Debug, PartialEq)]
+pub enum AvroError {
+    /// The input was not valid JSON.
+    InvalidJson(String),
+    /// The top-level schema was not an Avro `record`.
+    NotARecord,
+    /// The record (or one of its fields) did not have a `name`.
+    MissingName,
+    /// The record did not have a `fields` array.
+    MissingFields,
+    /// A field did not have a `type`.
+    MissingType(String),
+    /// A field's `type` could not be mapped onto a `FieldType`.
+    UnsupportedType(String),
+}
+
+impl fmt::Display for AvroError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AvroError::InvalidJson(reason) => write!(f, "invalid Avro JSON: {}", reason),
+            AvroError::NotARecord => write!(f, "top-level Avro schema must be a record"),
+            AvroError::MissingName => write!(f, "Avro schema is missing a `name`"),
+            AvroError::MissingFields => write!(f, "Avro record is missing a `fields` array"),
+            AvroError::MissingType(name) => write!(f, "Avro field `{}` is missing a `type`", name),
+            AvroError::UnsupportedType(type_) => {
+                write!(f, "unsupported Avro type: {}", type_)
+            }
+        }
+    }
+}
+
+impl std::error::Error for AvroError {}
+
+impl Schema {
+    /// Parse an Avro record schema and map it onto a `Schema`.
+    ///
+    /// The record's `name` becomes the table name, each record field becomes
+    /// a column (skipping any field whose name collides with a
+    /// `SystemField`, same as `Schema::add_field`), and a `["null", T]`
+    /// union marks the column nullable. Non-record top-level schemas are
+    /// rejected with `AvroError::NotARecord`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use collections::schema::Schema;
+    ///
+    /// let avro = r#"{
+    ///     "type": "record",
+    ///     "name": "users",
+    ///     "fields": [
+    ///         {"name": "name", "type": "string"},
+    ///         {"name": "age", "type": ["null", "int"]}
+    ///     ]
+    /// }"#;
+    ///
+    /// let schema = Schema::from_avro(avro).unwrap();
+    ///
+    /// assert_eq!(schema.table_name, "users");
+    /// assert_eq!(schema.fields.len(), 2);
+    /// ```
+    pub fn from_avro(json: &str) -> Result<Schema, AvroError> {
+        let value: Value =
+            serde_json::from_str(json).map_err(|err| AvroError::InvalidJson(err.to_string()))?;
+
+        let object = value.as_object().ok_or(AvroError::NotARecord)?;
+
+        if object.get("type").and_then(Value::as_str) != Some("record") {
+            return Err(AvroError::NotARecord);
+        }
+
+        let table_name = object
+            .get("name")
+            .and_then(Value::as_str)
+            .ok_or(AvroError::MissingName)?;
+
+        let fields = object
+            .get("fields")
+            .and_then(Value::as_array)
+            .ok_or(AvroError::MissingFields)?;
+
+        let mut schema = Schema::new();
+        schema.table_name = table_name.to_string();
+
+        for field in fields {
+            let field = field.as_object().ok_or(AvroError::MissingName)?;
+
+            let name = field
+                .get("name")
+                .and_then(Value::as_str)
+                .ok_or(AvroError::MissingName)?;
+
+            let type_ = field
+                .get("type")
+                .ok_or_else(|| AvroError::MissingType(name.to_string()))?;
+
+            let (field_type, not_null) = resolve_type(type_)?;
+            let default = field.get("default").and_then(default_to_string);
+
+            schema.add_field(name, field_type, Some(FieldOptions::new(false, not_null, default)));
+        }
+
+        Ok(schema)
+    }
+}
+
+/// Resolve an Avro field `type` into a `FieldType` plus whether the column
+/// should be `NOT NULL` (bare types are required, `["null", T]` unions are
+/// not).
+fn resolve_type(type_: &Value) -> Result<(FieldType, bool), AvroError> {
+    match type_ {
+        Value::String(name) => Ok((primitive_type(name)?, true)),
+        Value::Object(object) => {
+            if let Some(logical_type) = object.get("logicalType").and_then(Value::as_str) {
+                Ok((logical_type_to_field_type(logical_type)?, true))
+            } else if let Some(name) = object.get("type").and_then(Value::as_str) {
+                Ok((primitive_type(name)?, true))
+            } else {
+                Err(AvroError::UnsupportedType(type_.to_string()))
+            }
+        }
+        Value::Array(variants) => {
+            let null = Value::String("null".to_string());
+
+            if variants.len() != 2 || !variants.contains(&null) {
+                return Err(AvroError::UnsupportedType(type_.to_string()));
+            }
+
+            let inner = variants
+                .iter()
+                .find(|variant| *variant != &null)
+                .ok_or_else(|| AvroError::UnsupportedType(type_.to_string()))?;
+            let (field_type, _) = resolve_type(inner)?;
+
+            Ok((field_type, false))
+        }
+        _ => Err(AvroError::UnsupportedType(type_.to_string())),
+    }
+}
+
+fn primitive_type(name: &str) -> Result<FieldType, AvroError> {
+    match name {
+        "long" | "int" => Ok(FieldType::Integer),
+        "double" | "float" => Ok(FieldType::Double),
+        "string" | "bytes" => Ok(FieldType::Text),
+        "boolean" => Ok(FieldType::Boolean),
+        other => Err(AvroError::UnsupportedType(other.to_string())),
+    }
+}
+
+fn logical_type_to_field_type(logical_type: &str) -> Result<FieldType, AvroError> {
+    match logical_type {
+        "timestamp-millis" => Ok(FieldType::Timestamp),
+        "date" => Ok(FieldType::Date),
+        "time-millis" => Ok(FieldType::Time),
+        "uuid" => Ok(FieldType::UUID),
+        other => Err(AvroError::UnsupportedType(other.to_string())),
+    }
+}
+
+fn default_to_string(value: &Value) -> Option<String> {
+    match value {
+        Value::String(s) => Some(s.clone()),
+        Value::Number(n) => Some(n.to_string()),
+        Value::Bool(b) => Some(b.to_string()),
+        _ => None,
+    }
+}
@@ -0,0 +1,146 @@
+//! SQL dialects understood when rendering a `Schema` to DDL.
+//!
+//! Each logical `FieldType`/`SystemField` can render differently depending
+//! on the target database; a dialect is the single place that decides how.
+//! Adding a new dialect is one match arm per type rather than edits
+//! scattered across `field.rs` and `schema.rs`.
+
+use crate::field::{FieldType, SystemField};
+
+/// A SQL dialect understood by `Schema::to_sql_for`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SqlDialect {
+    Postgres,
+    MySql,
+    Sqlite,
+}
+
+impl SqlDialect {
+    /// Quote a table or column identifier the way this dialect expects.
+    /// MySQL wraps identifiers in backticks; Postgres and SQLite are left
+    /// bare, matching the crate's historical (unquoted) output.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use collections::dialect::SqlDialect;
+    ///
+    /// assert_eq!(SqlDialect::Postgres.quote_identifier("users"), "users");
+    /// assert_eq!(SqlDialect::MySql.quote_identifier("users"), "`users`");
+    /// ```
+    pub fn quote_identifier(&self, name: &str) -> String {
+        match self {
+            SqlDialect::MySql => format!("`{}`", name),
+            SqlDialect::Postgres | SqlDialect::Sqlite => name.to_string(),
+        }
+    }
+
+    /// Render a `FieldType` as it should appear for this dialect.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use collections::dialect::SqlDialect;
+    /// use collections::field::FieldType;
+    ///
+    /// assert_eq!(SqlDialect::Postgres.field_type_sql(&FieldType::UUID), "UUID");
+    /// assert_eq!(SqlDialect::MySql.field_type_sql(&FieldType::UUID), "CHAR(36)");
+    /// assert_eq!(SqlDialect::Sqlite.field_type_sql(&FieldType::UUID), "TEXT");
+    /// ```
+    pub fn field_type_sql(&self, field_type: &FieldType) -> &'static str {
+        match (self, field_type) {
+            (SqlDialect::Postgres, FieldType::Integer) => "BIGINT",
+            (SqlDialect::MySql, FieldType::Integer) => "BIGINT",
+            (SqlDialect::Sqlite, FieldType::Integer) => "INTEGER",
+
+            (SqlDialect::Postgres, FieldType::Double) => "DOUBLE PRECISION",
+            (SqlDialect::MySql, FieldType::Double) => "DOUBLE",
+            (SqlDialect::Sqlite, FieldType::Double) => "REAL",
+
+            (SqlDialect::Postgres, FieldType::Serial) => "BIGSERIAL",
+            (SqlDialect::MySql, FieldType::Serial) => "BIGINT AUTO_INCREMENT",
+            (SqlDialect::Sqlite, FieldType::Serial) => "INTEGER",
+
+            (SqlDialect::Postgres, FieldType::Char) => "VARCHAR(255)",
+            (SqlDialect::MySql, FieldType::Char) => "VARCHAR(255)",
+            (SqlDialect::Sqlite, FieldType::Char) => "TEXT",
+
+            (_, FieldType::Text) => "TEXT",
+
+            (SqlDialect::Postgres, FieldType::Timestamp) => "TIMESTAMP WITHOUT TIME ZONE",
+            (SqlDialect::MySql, FieldType::Timestamp) => "DATETIME",
+            (SqlDialect::Sqlite, FieldType::Timestamp) => "TIMESTAMP",
+
+            (_, FieldType::Date) => "DATE",
+            (_, FieldType::Time) => "TIME",
+
+            (SqlDialect::Postgres, FieldType::Boolean) => "BOOLEAN",
+            (SqlDialect::MySql, FieldType::Boolean) => "TINYINT(1)",
+            (SqlDialect::Sqlite, FieldType::Boolean) => "INTEGER",
+
+            (_, FieldType::Json) => "JSON",
+
+            (SqlDialect::Postgres, FieldType::Jsonb) => "JSONB",
+            (SqlDialect::MySql, FieldType::Jsonb) => "JSON",
+            (SqlDialect::Sqlite, FieldType::Jsonb) => "TEXT",
+
+            (SqlDialect::Postgres, FieldType::UUID) => "UUID",
+            (SqlDialect::MySql, FieldType::UUID) => "CHAR(36)",
+            (SqlDialect::Sqlite, FieldType::UUID) => "TEXT",
+        }
+    }
+
+    /// Render a `SystemField`'s full column definition (name, type, and its
+    /// PRIMARY KEY/DEFAULT clause) for this dialect.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use collections::dialect::SqlDialect;
+    /// use collections::field::SystemField;
+    ///
+    /// assert_eq!(
+    ///     SqlDialect::Postgres.system_field_sql(&SystemField::Id),
+    ///     "id UUID PRIMARY KEY DEFAULT gen_random_uuid()"
+    /// );
+    /// assert_eq!(
+    ///     SqlDialect::MySql.system_field_sql(&SystemField::Id),
+    ///     "`id` CHAR(36) PRIMARY KEY DEFAULT (UUID())"
+    /// );
+    /// assert_eq!(
+    ///     SqlDialect::Sqlite.system_field_sql(&SystemField::Id),
+    ///     "id TEXT PRIMARY KEY"
+    /// );
+    /// ```
+    pub fn system_field_sql(&self, field: &SystemField) -> String {
+        let options = match (self, field) {
+            (SqlDialect::Postgres, SystemField::Id) => {
+                "UUID PRIMARY KEY DEFAULT gen_random_uuid()"
+            }
+            (SqlDialect::MySql, SystemField::Id) => "CHAR(36) PRIMARY KEY DEFAULT (UUID())",
+            (SqlDialect::Sqlite, SystemField::Id) => "TEXT PRIMARY KEY",
+
+            (SqlDialect::Postgres, SystemField::InsertedAt) => {
+                "TIMESTAMP without time zone NOT NULL"
+            }
+            (SqlDialect::MySql, SystemField::InsertedAt) => "DATETIME NOT NULL",
+            (SqlDialect::Sqlite, SystemField::InsertedAt) => "TIMESTAMP NOT NULL",
+
+            (SqlDialect::Postgres, SystemField::UpdatedAt) => {
+                "TIMESTAMP without time zone NOT NULL"
+            }
+            (SqlDialect::MySql, SystemField::UpdatedAt) => "DATETIME NOT NULL",
+            (SqlDialect::Sqlite, SystemField::UpdatedAt) => "TIMESTAMP NOT NULL",
+        };
+
+        format!("{} {}", self.quote_identifier(&field.to_string()), options)
+    }
+}
+
+impl Default for SqlDialect {
+    /// The crate's historical behavior targeted Postgres exclusively, so
+    /// that remains the default dialect.
+    fn default() -> Self {
+        SqlDialect::Postgres
+    }
+}
@@ -1,6 +1,8 @@
 use std::fmt::Display;
 use std::slice::Iter;
 
+use crate::dialect::SqlDialect;
+
 /// The type of a field. This is used to determine the type of the field when
 /// creating a table.
 ///
@@ -39,6 +41,9 @@ use std::slice::Iter;
 /// let field_type = FieldType::Json;
 /// assert_eq!(field_type.to_string(), "JSON");
 ///
+/// let field_type = FieldType::Jsonb;
+/// assert_eq!(field_type.to_string(), "JSONB");
+///
 /// let field_type = FieldType::UUID;
 /// assert_eq!(field_type.to_string(), "UUID");
 /// ```
@@ -54,6 +59,10 @@ pub enum FieldType {
     Time,
     Boolean,
     Json,
+    /// A binary, indexable JSON document. Unlike `Json`, a `Jsonb` field can
+    /// declare `FieldOptions.jsonb_paths` to get expression indexes over
+    /// specific keys inside the document.
+    Jsonb,
     UUID,
 }
 
@@ -70,6 +79,7 @@ impl Display for FieldType {
             FieldType::Time => write!(f, "TIME"),
             FieldType::Boolean => write!(f, "BOOLEAN"),
             FieldType::Json => write!(f, "JSON"),
+            FieldType::Jsonb => write!(f, "JSONB"),
             FieldType::UUID => write!(f, "UUID"),
         }
     }
@@ -165,15 +175,7 @@ impl SystemField {
         names
     }
 
-    fn to_sql_options(&self) -> &str {
-        match self {
-            SystemField::Id => "UUID PRIMARY KEY DEFAULT gen_random_uuid()",
-            SystemField::InsertedAt => "TIMESTAMP without time zone NOT NULL",
-            SystemField::UpdatedAt => "TIMESTAMP without time zone NOT NULL",
-        }
-    }
-
-    /// Get the SQL for the system field.
+    /// Get the SQL for the system field, rendered for Postgres.
     /// The system fields are:
     ///
     /// * id
@@ -195,7 +197,22 @@ impl SystemField {
     /// assert_eq!(sql, "updated_at TIMESTAMP without time zone NOT NULL");
     /// ```
     pub fn to_sql(&self) -> String {
-        format!("{} {}", self, self.to_sql_options())
+        self.to_sql_for(SqlDialect::Postgres)
+    }
+
+    /// Get the SQL for the system field, rendered for `dialect`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use collections::dialect::SqlDialect;
+    /// use collections::field::SystemField;
+    ///
+    /// let sql = SystemField::Id.to_sql_for(SqlDialect::MySql);
+    /// assert_eq!(sql, "`id` CHAR(36) PRIMARY KEY DEFAULT (UUID())");
+    /// ```
+    pub fn to_sql_for(&self, dialect: SqlDialect) -> String {
+        dialect.system_field_sql(self)
     }
 }
 
@@ -204,11 +221,20 @@ pub struct FieldOptions {
     pub unique: bool,
     pub not_null: bool,
     pub default: Option<String>,
+    /// The target table and column this field references, if any. When
+    /// present, `Schema::to_sql` emits a `FOREIGN KEY` constraint for the
+    /// field.
+    pub references: Option<(String, String)>,
+    /// Expression paths into a `FieldType::Jsonb` document (e.g.
+    /// `data->'user'->>'id'`) that should each get their own GIN
+    /// expression index.
+    pub jsonb_paths: Vec<String>,
 }
 
 impl FieldOptions {
     /// Create a new FieldOptions struct with the given options. All options
-    /// are optional.
+    /// are optional. `references` defaults to `None`; use `with_reference`
+    /// to set it.
     ///
     /// # Example
     ///
@@ -220,14 +246,51 @@ impl FieldOptions {
     /// assert!(options.unique);
     /// assert!(options.not_null);
     /// assert_eq!(options.default, Some("default".to_string()));
+    /// assert_eq!(options.references, None);
     /// ```
     pub fn new(unique: bool, not_null: bool, default: Option<String>) -> Self {
         Self {
             unique,
             not_null,
             default,
+            references: None,
+            jsonb_paths: vec![],
         }
     }
+
+    /// Mark this field as a foreign key referencing `column` on `table`
+    /// (typically the target table's primary key, `id`).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use collections::field::FieldOptions;
+    ///
+    /// let options = FieldOptions::default().with_reference("users", "id");
+    ///
+    /// assert_eq!(options.references, Some(("users".to_string(), "id".to_string())));
+    /// ```
+    pub fn with_reference(mut self, table: &str, column: &str) -> Self {
+        self.references = Some((table.to_string(), column.to_string()));
+        self
+    }
+
+    /// Add an indexed JSON path expression (e.g. `data->'user'->>'id'`) to
+    /// a `FieldType::Jsonb` field.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use collections::field::FieldOptions;
+    ///
+    /// let options = FieldOptions::default().with_jsonb_path("data->'user'->>'id'");
+    ///
+    /// assert_eq!(options.jsonb_paths, vec!["data->'user'->>'id'".to_string()]);
+    /// ```
+    pub fn with_jsonb_path(mut self, path: &str) -> Self {
+        self.jsonb_paths.push(path.to_string());
+        self
+    }
 }
 
 impl Default for FieldOptions {
@@ -236,6 +299,8 @@ impl Default for FieldOptions {
     /// * unique: false
     /// * not_null: false
     /// * default: None
+    /// * references: None
+    /// * jsonb_paths: empty
     ///
     /// # Example
     ///
@@ -247,12 +312,15 @@ impl Default for FieldOptions {
     /// assert!(!options.unique);
     /// assert!(!options.not_null);
     /// assert_eq!(options.default, None);
+    /// assert_eq!(options.references, None);
+    /// assert!(options.jsonb_paths.is_empty());
     /// ```
     fn default() -> Self {
         Self::new(false, false, None)
     }
 }
 
+#[derive(Debug)]
 pub struct Field {
     pub name: String,
     pub type_: FieldType,
@@ -308,8 +376,18 @@ impl Field {
         }
     }
 
+    /// Render this field's column definition for Postgres.
     pub fn to_sql(&self) -> String {
-        let mut sql = format!("{} {}", self.name, self.type_);
+        self.to_sql_for(SqlDialect::Postgres)
+    }
+
+    /// Render this field's column definition for `dialect`.
+    pub fn to_sql_for(&self, dialect: SqlDialect) -> String {
+        let mut sql = format!(
+            "{} {}",
+            dialect.quote_identifier(&self.name),
+            dialect.field_type_sql(&self.type_)
+        );
 
         if self.has_options() {
             sql.push_str(self.not_null_sql());
@@ -333,7 +411,7 @@ impl Field {
         self.options.is_some()
     }
 
-    fn not_null_option(&self) -> bool {
+    pub(crate) fn not_null_option(&self) -> bool {
         match &self.options {
             Some(options) => options.not_null,
             None => false,
@@ -368,14 +446,24 @@ impl Field {
     }
 
     fn default_sql(&self) -> String {
-        if self.has_default() {
-            if self.is_numeric_field() || self.is_boolean_field() {
-                return format!(" DEFAULT {}", self.default_value());
-            } else {
-                return format!(" DEFAULT '{}'", self.default_value());
-            }
+        match self.default_literal() {
+            Some(literal) => format!(" DEFAULT {}", literal),
+            None => String::new(),
         }
+    }
 
-        String::new()
+    /// The field's default value rendered as a SQL literal (quoted unless
+    /// numeric or boolean), without the `DEFAULT` keyword. Used by
+    /// `Schema::diff` to build `SET DEFAULT` clauses.
+    pub(crate) fn default_literal(&self) -> Option<String> {
+        if !self.has_default() {
+            return None;
+        }
+
+        if self.is_numeric_field() || self.is_boolean_field() {
+            Some(self.default_value())
+        } else {
+            Some(format!("'{}'", self.default_value()))
+        }
     }
 }
@@ -0,0 +1,165 @@
+//! Infer a collection's `Field`s from sample JSON records, so a collection
+//! can be declared from example data instead of a hand-written schema.
+
+use std::collections::HashSet;
+
+use serde_json::Value;
+
+use crate::field::{Field, FieldOptions, FieldType, Fields};
+use crate::parsing::{is_date, is_timestamp, is_uuid};
+
+/// Infer the field list from a slice of example JSON records, with fields
+/// returned in sorted key order.
+///
+/// Keys are sorted explicitly rather than relying on the iteration order of
+/// the underlying `serde_json::Map` (a `BTreeMap` sorted by key unless the
+/// crate is built with `preserve_order`, in which case it would otherwise be
+/// insertion order), so the field order is deterministic regardless of how
+/// `serde_json` is built.
+///
+/// Each key's observed values are unified into a single `FieldType` using
+/// a deterministic numeric promotion ladder (`i64` before `u64` before
+/// `f64`, widening to `Double` when a key appears as both an integer and a
+/// floating-point value across records), with `Timestamp`/`Date`/`UUID`
+/// inferred by attempting the respective string parses before defaulting
+/// to `Char`/`Text`. Mixing incompatible types (e.g. a string and an
+/// object) falls back to `Json`. A field is `not_null` only if every
+/// record has it present and non-null.
+///
+/// # Example
+///
+/// ```
+/// use collections::inference::infer_fields;
+/// use collections::field::FieldType;
+/// use serde_json::json;
+///
+/// let records = vec![
+///     json!({"name": "Ada", "age": 30}),
+///     json!({"name": "Grace", "age": 31.5}),
+/// ];
+///
+/// let fields = infer_fields(&records);
+///
+/// assert_eq!(fields[0].name, "age");
+/// assert_eq!(fields[0].type_, FieldType::Double);
+/// assert_eq!(fields[1].name, "name");
+/// assert_eq!(fields[1].type_, FieldType::Char);
+/// ```
+pub fn infer_fields(records: &[Value]) -> Fields {
+    let mut order: Vec<String> = vec![];
+    let mut seen: HashSet<String> = HashSet::new();
+
+    for record in records {
+        let Some(object) = record.as_object() else {
+            continue;
+        };
+
+        for key in object.keys() {
+            if seen.insert(key.clone()) {
+                order.push(key.clone());
+            }
+        }
+    }
+
+    order.sort();
+
+    order
+        .into_iter()
+        .map(|key| {
+            let mut kind: Option<ValueKind> = None;
+            let mut non_null_count = 0;
+
+            for record in records {
+                let Some(value) = record.as_object().and_then(|object| object.get(&key)) else {
+                    continue;
+                };
+
+                if value.is_null() {
+                    continue;
+                }
+
+                non_null_count += 1;
+                let value_kind = classify_value(value);
+
+                kind = Some(match kind {
+                    Some(existing) => unify(existing, value_kind),
+                    None => value_kind,
+                });
+            }
+
+            let not_null = non_null_count == records.len();
+            let field_type = kind.map(ValueKind::into_field_type).unwrap_or(FieldType::Text);
+
+            Field::new(&key, field_type, Some(FieldOptions::new(false, not_null, None)))
+        })
+        .collect()
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum ValueKind {
+    Integer,
+    Double,
+    Boolean,
+    Timestamp,
+    Date,
+    Uuid,
+    Text(usize),
+    Json,
+}
+
+impl ValueKind {
+    fn into_field_type(self) -> FieldType {
+        match self {
+            ValueKind::Integer => FieldType::Integer,
+            ValueKind::Double => FieldType::Double,
+            ValueKind::Boolean => FieldType::Boolean,
+            ValueKind::Timestamp => FieldType::Timestamp,
+            ValueKind::Date => FieldType::Date,
+            ValueKind::Uuid => FieldType::UUID,
+            ValueKind::Text(max_length) if max_length <= 255 => FieldType::Char,
+            ValueKind::Text(_) => FieldType::Text,
+            ValueKind::Json => FieldType::Json,
+        }
+    }
+}
+
+fn unify(a: ValueKind, b: ValueKind) -> ValueKind {
+    match (a, b) {
+        (ValueKind::Integer, ValueKind::Integer) => ValueKind::Integer,
+        (ValueKind::Integer, ValueKind::Double) | (ValueKind::Double, ValueKind::Integer) => {
+            ValueKind::Double
+        }
+        (ValueKind::Double, ValueKind::Double) => ValueKind::Double,
+        (ValueKind::Text(a_len), ValueKind::Text(b_len)) => ValueKind::Text(a_len.max(b_len)),
+        (a, b) if a == b => a,
+        _ => ValueKind::Json,
+    }
+}
+
+fn classify_value(value: &Value) -> ValueKind {
+    match value {
+        Value::Bool(_) => ValueKind::Boolean,
+        Value::Object(_) | Value::Array(_) => ValueKind::Json,
+        Value::Number(number) => numeric_kind(&number.to_string()).unwrap_or(ValueKind::Double),
+        Value::String(s) => numeric_kind(s)
+            .or_else(|| is_date(s).then_some(ValueKind::Date))
+            .or_else(|| is_timestamp(s).then_some(ValueKind::Timestamp))
+            .or_else(|| is_uuid(s).then_some(ValueKind::Uuid))
+            .unwrap_or(ValueKind::Text(s.len())),
+        Value::Null => unreachable!("null values are filtered out before classification"),
+    }
+}
+
+/// Tries `i64` then `u64` (covering values like `u64::MAX` that overflow
+/// `i64`) before falling back to `f64`, so the same records always yield
+/// the same inferred type across runs.
+fn numeric_kind(value: &str) -> Option<ValueKind> {
+    if value.parse::<i64>().is_ok() || value.parse::<u64>().is_ok() {
+        Some(ValueKind::Integer)
+    } else if value.parse::<f64>().is_ok() {
+        Some(ValueKind::Double)
+    } else {
+        None
+    }
+}
+
@@ -0,0 +1,183 @@
+//! Import `Field` definitions from a draft-07-style JSON Schema document so
+//! a collection can be declared from an existing schema instead of
+//! hand-building every `Field`.
+//!
+//! Properties are mapped to `Field`s in sorted key order. This is
+//! determined explicitly (sorting the property names ourselves) rather
+//! than relying on the iteration order of the underlying
+//! `serde_json::Map`, so the result is deterministic regardless of
+//! whether the crate is built with serde_json's `preserve_order` feature.
+
+use std::fmt;
+
+use serde_json::{Map, Value};
+
+use crate::field::{Field, FieldOptions, FieldType};
+use crate::schema::Schema;
+
+/// Errors produced while mapping a JSON Schema document onto `Field`s.
+#[derive(Debug, PartialEq)]
+pub enum JsonSchemaError {
+    /// The input was not valid JSON.
+    InvalidJson(String),
+    /// The top-level schema was not an `"object"` schema.
+    NotAnObjectSchema,
+    /// The schema did not have a `properties` object.
+    MissingProperties,
+    /// A property's `type` could not be mapped onto a `FieldType`.
+    UnsupportedType(String),
+}
+
+impl fmt::Display for JsonSchemaError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            JsonSchemaError::InvalidJson(reason) => write!(f, "invalid JSON Schema: {}", reason),
+            JsonSchemaError::NotAnObjectSchema => {
+                write!(f, "top-level JSON Schema must have \"type\": \"object\"")
+            }
+            JsonSchemaError::MissingProperties => {
+                write!(f, "JSON Schema is missing a \"properties\" object")
+            }
+            JsonSchemaError::UnsupportedType(type_) => {
+                write!(f, "unsupported JSON Schema type: {}", type_)
+            }
+        }
+    }
+}
+
+impl std::error::Error for JsonSchemaError {}
+
+impl Schema {
+    /// Parse a draft-07-style JSON Schema object and map its `properties`
+    /// onto `Field`s, in sorted property-name order.
+    ///
+    /// `required` properties become `not_null`, a property `default`
+    /// becomes `FieldOptions.default`, and `uniqueItems`/a custom `unique`
+    /// marker becomes `FieldOptions.unique`. The schema's `title`, if
+    /// present, becomes the table name.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use collections::schema::Schema;
+    ///
+    /// let json_schema = r#"{
+    ///     "type": "object",
+    ///     "title": "users",
+    ///     "required": ["name"],
+    ///     "properties": {
+    ///         "name": {"type": "string", "maxLength": 120},
+    ///         "age": {"type": "integer"}
+    ///     }
+    /// }"#;
+    ///
+    /// let schema = Schema::from_json_schema(json_schema).unwrap();
+    ///
+    /// assert_eq!(schema.table_name, "users");
+    /// assert_eq!(schema.fields.len(), 2);
+    /// ```
+    pub fn from_json_schema(json: &str) -> Result<Schema, JsonSchemaError> {
+        let value: Value = serde_json::from_str(json)
+            .map_err(|err| JsonSchemaError::InvalidJson(err.to_string()))?;
+
+        let object = value.as_object().ok_or(JsonSchemaError::NotAnObjectSchema)?;
+
+        if object.get("type").and_then(Value::as_str) != Some("object") {
+            return Err(JsonSchemaError::NotAnObjectSchema);
+        }
+
+        let properties = object
+            .get("properties")
+            .and_then(Value::as_object)
+            .ok_or(JsonSchemaError::MissingProperties)?;
+
+        let required: Vec<&str> = object
+            .get("required")
+            .and_then(Value::as_array)
+            .map(|values| values.iter().filter_map(Value::as_str).collect())
+            .unwrap_or_default();
+
+        let mut schema = Schema::new();
+
+        if let Some(title) = object.get("title").and_then(Value::as_str) {
+            schema.table_name = title.to_string();
+        }
+
+        let mut property_names: Vec<&String> = properties.keys().collect();
+        property_names.sort();
+
+        for name in property_names {
+            let definition = properties.get(name).expect("key came from properties.keys()");
+            let definition = definition
+                .as_object()
+                .ok_or_else(|| JsonSchemaError::UnsupportedType(definition.to_string()))?;
+
+            let not_null = required.contains(&name.as_str());
+            let field = Field::from_json_schema(name, definition, not_null)?;
+
+            schema.add_field(&field.name, field.type_, field.options);
+        }
+
+        Ok(schema)
+    }
+}
+
+impl Field {
+    /// Map a single JSON Schema property definition onto a `Field`.
+    pub fn from_json_schema(
+        name: &str,
+        definition: &Map<String, Value>,
+        not_null: bool,
+    ) -> Result<Field, JsonSchemaError> {
+        let field_type = property_type(definition)?;
+
+        let unique = definition.get("uniqueItems").and_then(Value::as_bool).unwrap_or(false)
+            || definition.get("unique").and_then(Value::as_bool).unwrap_or(false);
+
+        let default = definition.get("default").and_then(default_to_string);
+
+        Ok(Field::new(
+            name,
+            field_type,
+            Some(FieldOptions::new(unique, not_null, default)),
+        ))
+    }
+}
+
+fn property_type(definition: &Map<String, Value>) -> Result<FieldType, JsonSchemaError> {
+    let type_ = definition
+        .get("type")
+        .and_then(Value::as_str)
+        .ok_or_else(|| JsonSchemaError::UnsupportedType(Value::Object(definition.clone()).to_string()))?;
+
+    match type_ {
+        "integer" => Ok(FieldType::Integer),
+        "number" => Ok(FieldType::Double),
+        "boolean" => Ok(FieldType::Boolean),
+        "object" | "array" => Ok(FieldType::Json),
+        "string" => Ok(string_type(definition)),
+        other => Err(JsonSchemaError::UnsupportedType(other.to_string())),
+    }
+}
+
+fn string_type(definition: &Map<String, Value>) -> FieldType {
+    match definition.get("format").and_then(Value::as_str) {
+        Some("date-time") => FieldType::Timestamp,
+        Some("date") => FieldType::Date,
+        Some("time") => FieldType::Time,
+        Some("uuid") => FieldType::UUID,
+        _ => match definition.get("maxLength").and_then(Value::as_u64) {
+            Some(max_length) if max_length <= 255 => FieldType::Char,
+            _ => FieldType::Text,
+        },
+    }
+}
+
+fn default_to_string(value: &Value) -> Option<String> {
+    match value {
+        Value::String(s) => Some(s.clone()),
+        Value::Number(n) => Some(n.to_string()),
+        Value::Bool(b) => Some(b.to_string()),
+        _ => None,
+    }
+}
@@ -0,0 +1,10 @@
+pub mod field;
+pub mod schema;
+
+pub mod avro;
+pub mod dialect;
+pub mod inference;
+pub mod json_schema;
+pub mod validation;
+
+mod parsing;
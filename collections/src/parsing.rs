@@ -0,0 +1,42 @@
+//! Lightweight string-format checks shared by `inference` (deciding a
+//! `FieldType` from sample values) and `validation` (checking a value
+//! against a `FieldType`). No date/UUID crate is pulled in for these —
+//! the formats involved are simple enough to check directly.
+
+pub(crate) fn is_date(value: &str) -> bool {
+    let parts: Vec<&str> = value.split('-').collect();
+
+    parts.len() == 3 && [4, 2, 2].iter().zip(&parts).all(|(len, part)| is_ascii_digits(part, *len))
+}
+
+pub(crate) fn is_timestamp(value: &str) -> bool {
+    let Some((date, time)) = value.split_once('T') else {
+        return false;
+    };
+
+    is_date(date) && is_time(time.trim_end_matches('Z'))
+}
+
+pub(crate) fn is_time(value: &str) -> bool {
+    let parts: Vec<&str> = value.split(':').collect();
+
+    parts.len() == 3
+        && is_ascii_digits(parts[0], 2)
+        && is_ascii_digits(parts[1], 2)
+        && parts[2].len() >= 2
+        && is_ascii_digits(&parts[2][..2], 2)
+}
+
+pub(crate) fn is_uuid(value: &str) -> bool {
+    let parts: Vec<&str> = value.split('-').collect();
+
+    parts.len() == 5
+        && [8, 4, 4, 4, 12]
+            .iter()
+            .zip(&parts)
+            .all(|(len, part)| part.len() == *len && part.chars().all(|c| c.is_ascii_hexdigit()))
+}
+
+fn is_ascii_digits(value: &str, length: usize) -> bool {
+    value.len() == length && value.chars().all(|c| c.is_ascii_digit())
+}
@@ -1,5 +1,7 @@
+use crate::dialect::SqlDialect;
 use crate::field::{Field, FieldOptions, FieldType, Fields, SystemField};
 
+#[derive(Debug)]
 pub struct Schema {
     pub table_name: String,
     pub fields: Fields,
@@ -101,19 +103,54 @@ impl Schema {
     /// assert_eq!(schema.to_sql(), sql_expected);
     /// ```
     pub fn to_sql(&self) -> String {
-        let mut sql = format!("CREATE TABLE {} (", self.table_name);
+        self.to_sql_for(SqlDialect::Postgres)
+    }
+
+    /// Generate the `CREATE TABLE` (and any trailing `CREATE INDEX`)
+    /// statements for `dialect`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use collections::dialect::SqlDialect;
+    /// use collections::schema::SchemaBuilder;
+    /// use collections::field::FieldType;
+    ///
+    /// let schema = SchemaBuilder::new()
+    ///    .with_table_name("users")
+    ///    .with_field("name", FieldType::Char, None)
+    ///    .build();
+    ///
+    /// assert_eq!(
+    ///     schema.to_sql_for(SqlDialect::MySql),
+    ///     "CREATE TABLE `users` (`id` CHAR(36) PRIMARY KEY DEFAULT (UUID()), `inserted_at` DATETIME NOT NULL, `updated_at` DATETIME NOT NULL, `name` VARCHAR(255));"
+    /// );
+    /// ```
+    pub fn to_sql_for(&self, dialect: SqlDialect) -> String {
+        let mut sql = format!("CREATE TABLE {} (", dialect.quote_identifier(&self.table_name));
         let mut constraints: Vec<String> = vec![];
+        let mut indexes: Vec<String> = vec![];
 
-        sql.push_str(&Self::system_fields_sql());
+        sql.push_str(&Self::system_fields_sql(dialect));
 
         for (index, field) in self.fields.iter().enumerate() {
-            sql.push_str(&field.to_sql());
+            sql.push_str(&field.to_sql_for(dialect));
 
             if let Some(constraints_sql) = self.unique_constraints_sql(field, field.options.clone())
             {
                 constraints.push(constraints_sql);
             }
 
+            if let Some(fkey_sql) = self.foreign_key_constraint_sql(field, field.options.clone()) {
+                constraints.push(fkey_sql);
+            }
+
+            if let Some(index_sql) = self.foreign_key_index_sql(field, field.options.clone()) {
+                indexes.push(index_sql);
+            }
+
+            indexes.extend(self.jsonb_index_sql(dialect, field));
+
             if index < self.fields.len() - 1 {
                 sql.push_str(", ");
             }
@@ -126,14 +163,19 @@ impl Schema {
 
         sql.push_str(");");
 
+        for index_sql in indexes {
+            sql.push(' ');
+            sql.push_str(&index_sql);
+        }
+
         sql
     }
 
-    fn system_fields_sql() -> String {
+    fn system_fields_sql(dialect: SqlDialect) -> String {
         let mut sql = String::new();
 
         for field in SystemField::iterator() {
-            sql.push_str(&field.to_sql());
+            sql.push_str(&field.to_sql_for(dialect));
             sql.push_str(", ");
         }
 
@@ -156,6 +198,351 @@ impl Schema {
 
         None
     }
+
+    fn foreign_key_constraint_sql(
+        &self,
+        field: &Field,
+        options: Option<FieldOptions>,
+    ) -> Option<String> {
+        let (table, column) = options?.references?;
+
+        Some(format!(
+            "CONSTRAINT {}_{}_fkey FOREIGN KEY ({}) REFERENCES {}({})",
+            self.table_name, field.name, field.name, table, column
+        ))
+    }
+
+    /// A non-unique foreign key doesn't get an index for free the way a
+    /// UNIQUE constraint does, so emit one explicitly to keep reverse
+    /// lookups from parent to children cheap.
+    fn foreign_key_index_sql(&self, field: &Field, options: Option<FieldOptions>) -> Option<String> {
+        let options = options?;
+
+        if options.references.is_none() || options.unique {
+            return None;
+        }
+
+        Some(format!(
+            "CREATE INDEX {}_{}_idx ON {}({});",
+            self.table_name, field.name, self.table_name, field.name
+        ))
+    }
+
+    /// Emit one `CREATE INDEX ... USING GIN` expression index per path a
+    /// `FieldType::Jsonb` field declares in `FieldOptions.jsonb_paths`, so
+    /// querying a specific key inside the document doesn't require a full
+    /// scan. GIN expression indexes are a Postgres-only feature, so other
+    /// dialects emit no index at all rather than invalid SQL.
+    fn jsonb_index_sql(&self, dialect: SqlDialect, field: &Field) -> Vec<String> {
+        if dialect != SqlDialect::Postgres || field.type_ != FieldType::Jsonb {
+            return vec![];
+        }
+
+        let Some(options) = &field.options else {
+            return vec![];
+        };
+
+        options
+            .jsonb_paths
+            .iter()
+            .map(|path| {
+                format!(
+                    "CREATE INDEX {}_{}_idx ON {} USING GIN (({}));",
+                    self.table_name,
+                    sanitize_jsonb_path(path),
+                    self.table_name,
+                    path
+                )
+            })
+            .collect()
+    }
+
+    /// Compare `self` against `previous` (an earlier version of the same
+    /// table) and emit the `ALTER TABLE` statements needed to evolve
+    /// `previous` into `self`.
+    ///
+    /// Columns are matched by name. New fields become `ADD COLUMN`
+    /// (reusing `Field::to_sql`), removed fields become `DROP COLUMN`, and a
+    /// field whose `FieldType` or `FieldOptions` changed becomes the
+    /// relevant `ALTER COLUMN`/`ADD CONSTRAINT`/`DROP CONSTRAINT`
+    /// statements. System fields never appear in the diff, and constraint
+    /// drops are ordered before column drops so a dropped foreign key or
+    /// unique constraint doesn't block dropping its column.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use collections::field::FieldType;
+    /// use collections::schema::SchemaBuilder;
+    ///
+    /// let previous = SchemaBuilder::new()
+    ///     .with_table_name("users")
+    ///     .with_field("name", FieldType::Char, None)
+    ///     .build();
+    ///
+    /// let current = SchemaBuilder::new()
+    ///     .with_table_name("users")
+    ///     .with_field("email", FieldType::Char, None)
+    ///     .build();
+    ///
+    /// let statements = current.diff(&previous);
+    ///
+    /// assert_eq!(
+    ///     statements,
+    ///     vec![
+    ///         "ALTER TABLE users DROP COLUMN name;".to_string(),
+    ///         "ALTER TABLE users ADD COLUMN email VARCHAR(255);".to_string(),
+    ///     ]
+    /// );
+    /// ```
+    pub fn diff(&self, previous: &Schema) -> Vec<String> {
+        let system_field_names = SystemField::names();
+
+        let current_fields: Vec<&Field> = self
+            .fields
+            .iter()
+            .filter(|field| !system_field_names.contains(&field.name))
+            .collect();
+        let previous_fields: Vec<&Field> = previous
+            .fields
+            .iter()
+            .filter(|field| !system_field_names.contains(&field.name))
+            .collect();
+
+        let mut drop_constraints: Vec<String> = vec![];
+        let mut drop_columns: Vec<String> = vec![];
+        let mut add_columns: Vec<String> = vec![];
+        let mut alter_columns: Vec<String> = vec![];
+        let mut add_constraints: Vec<String> = vec![];
+
+        for field in &previous_fields {
+            if current_fields.iter().any(|f| f.name == field.name) {
+                continue;
+            }
+
+            self.push_constraint_drops(field, &field.options, &mut drop_constraints);
+            drop_columns.push(format!(
+                "ALTER TABLE {} DROP COLUMN {};",
+                self.table_name, field.name
+            ));
+        }
+
+        for field in &current_fields {
+            let Some(previous_field) = previous_fields.iter().find(|f| f.name == field.name)
+            else {
+                add_columns.push(format!(
+                    "ALTER TABLE {} ADD COLUMN {};",
+                    self.table_name,
+                    field.to_sql()
+                ));
+
+                let new_options = field.options.clone().unwrap_or_default();
+                if new_options.unique {
+                    add_constraints.push(self.add_unique_constraint_sql(field));
+                }
+                if let Some((table, column)) = &new_options.references {
+                    add_constraints.push(self.add_foreign_key_constraint_sql(field, table, column));
+                }
+
+                continue;
+            };
+
+            if field.type_ != previous_field.type_ {
+                alter_columns.push(format!(
+                    "ALTER TABLE {} ALTER COLUMN {} TYPE {};",
+                    self.table_name, field.name, field.type_
+                ));
+            }
+
+            let current_options = field.options.clone().unwrap_or_default();
+            let previous_options = previous_field.options.clone().unwrap_or_default();
+
+            if current_options.not_null != previous_options.not_null {
+                let clause = if current_options.not_null {
+                    "SET NOT NULL"
+                } else {
+                    "DROP NOT NULL"
+                };
+                alter_columns.push(format!(
+                    "ALTER TABLE {} ALTER COLUMN {} {};",
+                    self.table_name, field.name, clause
+                ));
+            }
+
+            if current_options.default != previous_options.default {
+                match field.default_literal() {
+                    Some(literal) => alter_columns.push(format!(
+                        "ALTER TABLE {} ALTER COLUMN {} SET DEFAULT {};",
+                        self.table_name, field.name, literal
+                    )),
+                    None => alter_columns.push(format!(
+                        "ALTER TABLE {} ALTER COLUMN {} DROP DEFAULT;",
+                        self.table_name, field.name
+                    )),
+                }
+            }
+
+            if previous_options.unique && !current_options.unique {
+                drop_constraints.push(self.drop_unique_constraint_sql(field));
+            }
+            if current_options.unique && !previous_options.unique {
+                add_constraints.push(self.add_unique_constraint_sql(field));
+            }
+
+            if previous_options.references.is_some()
+                && current_options.references != previous_options.references
+            {
+                drop_constraints.push(self.drop_foreign_key_constraint_sql(field));
+            }
+            if let Some((table, column)) = &current_options.references {
+                if current_options.references != previous_options.references {
+                    add_constraints.push(self.add_foreign_key_constraint_sql(field, table, column));
+                }
+            }
+        }
+
+        let mut statements = vec![];
+        statements.extend(drop_constraints);
+        statements.extend(drop_columns);
+        statements.extend(add_columns);
+        statements.extend(alter_columns);
+        statements.extend(add_constraints);
+
+        statements
+    }
+
+    fn push_constraint_drops(
+        &self,
+        field: &Field,
+        options: &Option<FieldOptions>,
+        drop_constraints: &mut Vec<String>,
+    ) {
+        let Some(options) = options else {
+            return;
+        };
+
+        if options.unique {
+            drop_constraints.push(self.drop_unique_constraint_sql(field));
+        }
+
+        if options.references.is_some() {
+            drop_constraints.push(self.drop_foreign_key_constraint_sql(field));
+        }
+    }
+
+    fn add_unique_constraint_sql(&self, field: &Field) -> String {
+        format!(
+            "ALTER TABLE {} ADD CONSTRAINT {}_{}_key UNIQUE ({});",
+            self.table_name, self.table_name, field.name, field.name
+        )
+    }
+
+    fn drop_unique_constraint_sql(&self, field: &Field) -> String {
+        format!(
+            "ALTER TABLE {} DROP CONSTRAINT {}_{}_key;",
+            self.table_name, self.table_name, field.name
+        )
+    }
+
+    fn add_foreign_key_constraint_sql(&self, field: &Field, table: &str, column: &str) -> String {
+        format!(
+            "ALTER TABLE {} ADD CONSTRAINT {}_{}_fkey FOREIGN KEY ({}) REFERENCES {}({});",
+            self.table_name, self.table_name, field.name, field.name, table, column
+        )
+    }
+
+    fn drop_foreign_key_constraint_sql(&self, field: &Field) -> String {
+        format!(
+            "ALTER TABLE {} DROP CONSTRAINT {}_{}_fkey;",
+            self.table_name, self.table_name, field.name
+        )
+    }
+
+    /// Build the full migration from `previous` to `self`: the `diff`
+    /// statements plus warnings about changes that are unsafe on a
+    /// populated table (narrowing `Text` to `Char`, or adding `not_null`
+    /// without a `default`).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use collections::field::{FieldOptions, FieldType};
+    /// use collections::schema::SchemaBuilder;
+    ///
+    /// let previous = SchemaBuilder::new()
+    ///     .with_table_name("users")
+    ///     .with_field("bio", FieldType::Text, None)
+    ///     .build();
+    ///
+    /// let current = SchemaBuilder::new()
+    ///     .with_table_name("users")
+    ///     .with_field("bio", FieldType::Char, None)
+    ///     .build();
+    ///
+    /// let plan = current.migration_plan(&previous);
+    ///
+    /// assert_eq!(plan.statements, vec!["ALTER TABLE users ALTER COLUMN bio TYPE VARCHAR(255);".to_string()]);
+    /// assert_eq!(plan.warnings.len(), 1);
+    /// ```
+    pub fn migration_plan(&self, previous: &Schema) -> MigrationPlan {
+        MigrationPlan {
+            statements: self.diff(previous),
+            warnings: self.unsafe_change_warnings(previous),
+        }
+    }
+
+    fn unsafe_change_warnings(&self, previous: &Schema) -> Vec<String> {
+        let system_field_names = SystemField::names();
+
+        let current_fields: Vec<&Field> = self
+            .fields
+            .iter()
+            .filter(|field| !system_field_names.contains(&field.name))
+            .collect();
+        let previous_fields: Vec<&Field> = previous
+            .fields
+            .iter()
+            .filter(|field| !system_field_names.contains(&field.name))
+            .collect();
+
+        let mut warnings = vec![];
+
+        for field in &current_fields {
+            let Some(previous_field) = previous_fields.iter().find(|f| f.name == field.name)
+            else {
+                continue;
+            };
+
+            if previous_field.type_ == FieldType::Text && field.type_ == FieldType::Char {
+                warnings.push(format!(
+                    "narrowing column `{}` from TEXT to VARCHAR(255) may truncate existing data",
+                    field.name
+                ));
+            }
+
+            let current_options = field.options.clone().unwrap_or_default();
+            let previous_options = previous_field.options.clone().unwrap_or_default();
+
+            if current_options.not_null
+                && !previous_options.not_null
+                && current_options.default.is_none()
+            {
+                warnings.push(format!(
+                    "adding NOT NULL to column `{}` without a DEFAULT will fail on a populated table",
+                    field.name
+                ));
+            }
+        }
+
+        warnings
+    }
+}
+
+/// The statements and safety warnings produced by `Schema::migration_plan`.
+#[derive(Debug, PartialEq)]
+pub struct MigrationPlan {
+    pub statements: Vec<String>,
+    pub warnings: Vec<String>,
 }
 
 impl Default for Schema {
@@ -270,3 +657,23 @@ impl Default for SchemaBuilder {
         Self::new()
     }
 }
+
+/// Turn a JSON path expression like `data->'user'->>'id'` into a safe
+/// index-name fragment (`data_user_id`) by collapsing runs of
+/// non-alphanumeric characters into a single underscore.
+fn sanitize_jsonb_path(path: &str) -> String {
+    let mut sanitized = String::new();
+    let mut last_was_underscore = false;
+
+    for ch in path.chars() {
+        if ch.is_alphanumeric() {
+            sanitized.push(ch.to_ascii_lowercase());
+            last_was_underscore = false;
+        } else if !last_was_underscore {
+            sanitized.push('_');
+            last_was_underscore = true;
+        }
+    }
+
+    sanitized.trim_matches('_').to_string()
+}
@@ -0,0 +1,152 @@
+//! Validate a JSON value against a `Field`'s `FieldType` and
+//! `FieldOptions`, so bad payloads can be rejected before they reach SQL.
+
+use std::fmt;
+
+use serde_json::Value;
+
+use crate::field::{Field, FieldType};
+use crate::parsing::{is_date, is_time, is_timestamp, is_uuid};
+
+/// A value that does not satisfy a field's type or `not_null` option.
+#[derive(Debug, PartialEq)]
+pub struct FieldError {
+    pub field: String,
+    pub message: String,
+}
+
+impl FieldError {
+    fn new(field: &str, message: impl Into<String>) -> Self {
+        Self {
+            field: field.to_string(),
+            message: message.into(),
+        }
+    }
+}
+
+impl fmt::Display for FieldError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "field `{}`: {}", self.field, self.message)
+    }
+}
+
+impl std::error::Error for FieldError {}
+
+impl Field {
+    /// Validate `value` against this field's `FieldType` and, if present,
+    /// its `FieldOptions`. A JSON `null` (standing in for a missing key as
+    /// well) is only accepted when the field is not `not_null`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use collections::field::{Field, FieldType};
+    /// use serde_json::json;
+    ///
+    /// let field = Field::new("age", FieldType::Integer, None);
+    ///
+    /// assert!(field.validate(&json!(30)).is_ok());
+    /// assert!(field.validate(&json!("not a number")).is_err());
+    /// ```
+    pub fn validate(&self, value: &Value) -> Result<(), FieldError> {
+        if value.is_null() {
+            if self.not_null_option() {
+                return Err(FieldError::new(&self.name, "is required"));
+            }
+
+            return Ok(());
+        }
+
+        match self.type_ {
+            FieldType::Integer | FieldType::Serial => self.validate_integer(value),
+            FieldType::Double => self.validate_double(value),
+            FieldType::Boolean => self.validate_boolean(value),
+            FieldType::Char => self.validate_char(value),
+            FieldType::Text => self.validate_text(value),
+            FieldType::Json | FieldType::Jsonb => self.validate_json(value),
+            FieldType::UUID => self.validate_formatted_string(value, "a well-formed UUID", is_uuid),
+            FieldType::Timestamp => {
+                self.validate_formatted_string(value, "a parseable timestamp", is_timestamp)
+            }
+            FieldType::Date => self.validate_formatted_string(value, "a parseable date", is_date),
+            FieldType::Time => self.validate_formatted_string(value, "a parseable time", is_time),
+        }
+    }
+
+    fn validate_integer(&self, value: &Value) -> Result<(), FieldError> {
+        if value.is_i64() {
+            return Ok(());
+        }
+
+        if let Some(n) = value.as_u64() {
+            return if n <= i64::MAX as u64 {
+                Ok(())
+            } else {
+                Err(FieldError::new(&self.name, "is out of BIGINT range"))
+            };
+        }
+
+        Err(FieldError::new(&self.name, "must be an integer"))
+    }
+
+    fn validate_double(&self, value: &Value) -> Result<(), FieldError> {
+        if value.is_number() {
+            Ok(())
+        } else {
+            Err(FieldError::new(&self.name, "must be a number"))
+        }
+    }
+
+    fn validate_boolean(&self, value: &Value) -> Result<(), FieldError> {
+        if value.is_boolean() {
+            Ok(())
+        } else {
+            Err(FieldError::new(&self.name, "must be a boolean"))
+        }
+    }
+
+    fn validate_char(&self, value: &Value) -> Result<(), FieldError> {
+        let Some(s) = value.as_str() else {
+            return Err(FieldError::new(&self.name, "must be a string"));
+        };
+
+        if s.chars().count() > 255 {
+            return Err(FieldError::new(&self.name, "must be at most 255 characters"));
+        }
+
+        Ok(())
+    }
+
+    fn validate_text(&self, value: &Value) -> Result<(), FieldError> {
+        if value.is_string() {
+            Ok(())
+        } else {
+            Err(FieldError::new(&self.name, "must be a string"))
+        }
+    }
+
+    fn validate_json(&self, value: &Value) -> Result<(), FieldError> {
+        if value.is_object() || value.is_array() {
+            Ok(())
+        } else {
+            Err(FieldError::new(&self.name, "must be a JSON object or array"))
+        }
+    }
+
+    fn validate_formatted_string(
+        &self,
+        value: &Value,
+        expected: &str,
+        parses: fn(&str) -> bool,
+    ) -> Result<(), FieldError> {
+        let Some(s) = value.as_str() else {
+            return Err(FieldError::new(&self.name, format!("must be {}", expected)));
+        };
+
+        if parses(s) {
+            Ok(())
+        } else {
+            Err(FieldError::new(&self.name, format!("must be {}", expected)))
+        }
+    }
+}
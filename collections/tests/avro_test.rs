@@ -0,0 +1,111 @@
+use collections::avro::AvroError;
+use collections::field::FieldType;
+use collections::schema::Schema;
+
+#[test]
+fn test_from_avro_maps_primitives_and_logical_types() {
+    let avro = r#"{
+        "type": "record",
+        "name": "users",
+        "fields": [
+            {"name": "name", "type": "string"},
+            {"name": "age", "type": "int"},
+            {"name": "balance", "type": "double"},
+            {"name": "is_active", "type": "boolean"},
+            {"name": "created_at", "type": {"type": "long", "logicalType": "timestamp-millis"}},
+            {"name": "birthday", "type": {"type": "int", "logicalType": "date"}},
+            {"name": "reminder", "type": {"type": "int", "logicalType": "time-millis"}},
+            {"name": "external_id", "type": {"type": "string", "logicalType": "uuid"}}
+        ]
+    }"#;
+
+    let schema = Schema::from_avro(avro).unwrap();
+
+    assert_eq!(schema.table_name, "users");
+    assert_eq!(schema.fields.len(), 8);
+    assert_eq!(schema.fields[0].type_, FieldType::Text);
+    assert_eq!(schema.fields[1].type_, FieldType::Integer);
+    assert_eq!(schema.fields[2].type_, FieldType::Double);
+    assert_eq!(schema.fields[3].type_, FieldType::Boolean);
+    assert_eq!(schema.fields[4].type_, FieldType::Timestamp);
+    assert_eq!(schema.fields[5].type_, FieldType::Date);
+    assert_eq!(schema.fields[6].type_, FieldType::Time);
+    assert_eq!(schema.fields[7].type_, FieldType::UUID);
+}
+
+#[test]
+fn test_from_avro_nullable_union() {
+    let avro = r#"{
+        "type": "record",
+        "name": "users",
+        "fields": [
+            {"name": "name", "type": "string"},
+            {"name": "nickname", "type": ["null", "string"]}
+        ]
+    }"#;
+
+    let schema = Schema::from_avro(avro).unwrap();
+
+    assert!(schema.fields[0].options.as_ref().unwrap().not_null);
+    assert!(!schema.fields[1].options.as_ref().unwrap().not_null);
+}
+
+#[test]
+fn test_from_avro_default_value() {
+    let avro = r#"{
+        "type": "record",
+        "name": "users",
+        "fields": [
+            {"name": "age", "type": "int", "default": 18}
+        ]
+    }"#;
+
+    let schema = Schema::from_avro(avro).unwrap();
+
+    assert_eq!(
+        schema.fields[0].options.as_ref().unwrap().default,
+        Some("18".to_string())
+    );
+}
+
+#[test]
+fn test_from_avro_skips_system_fields() {
+    let avro = r#"{
+        "type": "record",
+        "name": "users",
+        "fields": [
+            {"name": "id", "type": "string"},
+            {"name": "inserted_at", "type": "string"},
+            {"name": "updated_at", "type": "string"},
+            {"name": "name", "type": "string"}
+        ]
+    }"#;
+
+    let schema = Schema::from_avro(avro).unwrap();
+
+    assert_eq!(schema.fields.len(), 1);
+    assert_eq!(schema.fields[0].name, "name");
+}
+
+#[test]
+fn test_from_avro_rejects_all_null_union() {
+    let avro = r#"{
+        "type": "record",
+        "name": "users",
+        "fields": [
+            {"name": "nickname", "type": ["null", "null"]}
+        ]
+    }"#;
+
+    assert!(matches!(
+        Schema::from_avro(avro).unwrap_err(),
+        AvroError::UnsupportedType(_)
+    ));
+}
+
+#[test]
+fn test_from_avro_rejects_non_record() {
+    let avro = r#"{"type": "string"}"#;
+
+    assert_eq!(Schema::from_avro(avro).unwrap_err(), AvroError::NotARecord);
+}
@@ -0,0 +1,65 @@
+use collections::dialect::SqlDialect;
+use collections::field::{FieldType, SystemField};
+
+#[test]
+fn test_field_type_sql_postgres() {
+    assert_eq!(SqlDialect::Postgres.field_type_sql(&FieldType::UUID), "UUID");
+    assert_eq!(
+        SqlDialect::Postgres.field_type_sql(&FieldType::Timestamp),
+        "TIMESTAMP WITHOUT TIME ZONE"
+    );
+    assert_eq!(SqlDialect::Postgres.field_type_sql(&FieldType::Boolean), "BOOLEAN");
+}
+
+#[test]
+fn test_field_type_sql_mysql() {
+    assert_eq!(SqlDialect::MySql.field_type_sql(&FieldType::UUID), "CHAR(36)");
+    assert_eq!(SqlDialect::MySql.field_type_sql(&FieldType::Timestamp), "DATETIME");
+    assert_eq!(SqlDialect::MySql.field_type_sql(&FieldType::Boolean), "TINYINT(1)");
+    assert_eq!(
+        SqlDialect::MySql.field_type_sql(&FieldType::Serial),
+        "BIGINT AUTO_INCREMENT"
+    );
+}
+
+#[test]
+fn test_field_type_sql_sqlite() {
+    assert_eq!(SqlDialect::Sqlite.field_type_sql(&FieldType::UUID), "TEXT");
+    assert_eq!(SqlDialect::Sqlite.field_type_sql(&FieldType::Double), "REAL");
+    assert_eq!(SqlDialect::Sqlite.field_type_sql(&FieldType::Boolean), "INTEGER");
+}
+
+#[test]
+fn test_field_type_sql_jsonb() {
+    assert_eq!(SqlDialect::Postgres.field_type_sql(&FieldType::Jsonb), "JSONB");
+    assert_eq!(SqlDialect::MySql.field_type_sql(&FieldType::Jsonb), "JSON");
+    assert_eq!(SqlDialect::Sqlite.field_type_sql(&FieldType::Jsonb), "TEXT");
+}
+
+#[test]
+fn test_system_field_sql_per_dialect() {
+    assert_eq!(
+        SqlDialect::Postgres.system_field_sql(&SystemField::Id),
+        "id UUID PRIMARY KEY DEFAULT gen_random_uuid()"
+    );
+    assert_eq!(
+        SqlDialect::MySql.system_field_sql(&SystemField::Id),
+        "`id` CHAR(36) PRIMARY KEY DEFAULT (UUID())"
+    );
+    assert_eq!(
+        SqlDialect::Sqlite.system_field_sql(&SystemField::Id),
+        "id TEXT PRIMARY KEY"
+    );
+}
+
+#[test]
+fn test_sql_dialect_default_is_postgres() {
+    assert_eq!(SqlDialect::default(), SqlDialect::Postgres);
+}
+
+#[test]
+fn test_quote_identifier() {
+    assert_eq!(SqlDialect::Postgres.quote_identifier("users"), "users");
+    assert_eq!(SqlDialect::Sqlite.quote_identifier("users"), "users");
+    assert_eq!(SqlDialect::MySql.quote_identifier("users"), "`users`");
+}
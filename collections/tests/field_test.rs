@@ -70,6 +70,13 @@ fn test_field_type_json() {
     assert_eq!(field_type.to_string(), "JSON");
 }
 
+#[test]
+fn test_field_type_jsonb() {
+    let field_type = FieldType::Jsonb;
+
+    assert_eq!(field_type.to_string(), "JSONB");
+}
+
 #[test]
 fn test_field_type_uuid() {
     let field_type = FieldType::UUID;
@@ -183,6 +190,23 @@ fn test_field_options_default() {
     assert!(!options.unique);
     assert!(!options.not_null);
     assert_eq!(options.default, None);
+    assert_eq!(options.references, None);
+    assert!(options.jsonb_paths.is_empty());
+}
+
+#[test]
+fn test_field_options_with_jsonb_path() {
+    let options = FieldOptions::default()
+        .with_jsonb_path("data->'user'->>'id'")
+        .with_jsonb_path("data->>'status'");
+
+    assert_eq!(
+        options.jsonb_paths,
+        vec![
+            "data->'user'->>'id'".to_string(),
+            "data->>'status'".to_string(),
+        ]
+    );
 }
 
 #[test]
@@ -0,0 +1,77 @@
+use collections::field::FieldType;
+use collections::inference::infer_fields;
+use serde_json::json;
+
+#[test]
+fn test_infer_fields_sorts_field_names_alphabetically() {
+    let records = vec![json!({"name": "Ada", "age": 30}), json!({"age": 31, "name": "Grace"})];
+
+    let fields = infer_fields(&records);
+
+    assert_eq!(fields[0].name, "age");
+    assert_eq!(fields[1].name, "name");
+}
+
+#[test]
+fn test_infer_fields_widens_integer_to_double() {
+    let records = vec![json!({"score": 10}), json!({"score": 10.5})];
+
+    let fields = infer_fields(&records);
+
+    assert_eq!(fields[0].type_, FieldType::Double);
+}
+
+#[test]
+fn test_infer_fields_numeric_ladder_prefers_integer() {
+    let records = vec![json!({"count": "10"}), json!({"count": 20})];
+
+    let fields = infer_fields(&records);
+
+    assert_eq!(fields[0].type_, FieldType::Integer);
+}
+
+#[test]
+fn test_infer_fields_mixed_incompatible_falls_back_to_json() {
+    let records = vec![json!({"value": "hello"}), json!({"value": {"a": 1}})];
+
+    let fields = infer_fields(&records);
+
+    assert_eq!(fields[0].type_, FieldType::Json);
+}
+
+#[test]
+fn test_infer_fields_date_timestamp_and_uuid() {
+    let records = vec![json!({
+        "created_at": "2024-01-02T03:04:05Z",
+        "birthday": "1990-05-01",
+        "external_id": "550e8400-e29b-41d4-a716-446655440000"
+    })];
+
+    let fields = infer_fields(&records);
+
+    assert_eq!(fields[0].name, "birthday");
+    assert_eq!(fields[0].type_, FieldType::Date);
+    assert_eq!(fields[1].name, "created_at");
+    assert_eq!(fields[1].type_, FieldType::Timestamp);
+    assert_eq!(fields[2].name, "external_id");
+    assert_eq!(fields[2].type_, FieldType::UUID);
+}
+
+#[test]
+fn test_infer_fields_not_null_requires_presence_in_every_sample() {
+    let records = vec![json!({"name": "Ada", "nickname": "Countess"}), json!({"name": "Grace"})];
+
+    let fields = infer_fields(&records);
+
+    assert!(fields[0].options.as_ref().unwrap().not_null);
+    assert!(!fields[1].options.as_ref().unwrap().not_null);
+}
+
+#[test]
+fn test_infer_fields_null_value_is_not_present() {
+    let records = vec![json!({"name": "Ada"}), json!({"name": null})];
+
+    let fields = infer_fields(&records);
+
+    assert!(!fields[0].options.as_ref().unwrap().not_null);
+}
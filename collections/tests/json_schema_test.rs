@@ -0,0 +1,99 @@
+use collections::field::FieldType;
+use collections::json_schema::JsonSchemaError;
+use collections::schema::Schema;
+
+#[test]
+fn test_from_json_schema_maps_types() {
+    let json_schema = r#"{
+        "type": "object",
+        "title": "users",
+        "required": ["name"],
+        "properties": {
+            "name": {"type": "string", "maxLength": 120},
+            "bio": {"type": "string"},
+            "age": {"type": "integer"},
+            "balance": {"type": "number"},
+            "is_active": {"type": "boolean"},
+            "created_at": {"type": "string", "format": "date-time"},
+            "birthday": {"type": "string", "format": "date"},
+            "reminder": {"type": "string", "format": "time"},
+            "external_id": {"type": "string", "format": "uuid"},
+            "metadata": {"type": "object"}
+        }
+    }"#;
+
+    let schema = Schema::from_json_schema(json_schema).unwrap();
+
+    assert_eq!(schema.table_name, "users");
+    assert_eq!(schema.fields.len(), 10);
+
+    // Properties come back in sorted key order, not declaration order.
+    assert_eq!(schema.fields[0].name, "age");
+    assert_eq!(schema.fields[0].type_, FieldType::Integer);
+    assert_eq!(schema.fields[1].name, "balance");
+    assert_eq!(schema.fields[1].type_, FieldType::Double);
+    assert_eq!(schema.fields[2].name, "bio");
+    assert_eq!(schema.fields[2].type_, FieldType::Text);
+    assert_eq!(schema.fields[3].name, "birthday");
+    assert_eq!(schema.fields[3].type_, FieldType::Date);
+    assert_eq!(schema.fields[4].name, "created_at");
+    assert_eq!(schema.fields[4].type_, FieldType::Timestamp);
+    assert_eq!(schema.fields[5].name, "external_id");
+    assert_eq!(schema.fields[5].type_, FieldType::UUID);
+    assert_eq!(schema.fields[6].name, "is_active");
+    assert_eq!(schema.fields[6].type_, FieldType::Boolean);
+    assert_eq!(schema.fields[7].name, "metadata");
+    assert_eq!(schema.fields[7].type_, FieldType::Json);
+    assert_eq!(schema.fields[8].name, "name");
+    assert_eq!(schema.fields[8].type_, FieldType::Char);
+    assert_eq!(schema.fields[9].name, "reminder");
+    assert_eq!(schema.fields[9].type_, FieldType::Time);
+}
+
+#[test]
+fn test_from_json_schema_required_and_default() {
+    let json_schema = r#"{
+        "type": "object",
+        "required": ["name"],
+        "properties": {
+            "name": {"type": "string"},
+            "age": {"type": "integer", "default": 18}
+        }
+    }"#;
+
+    let schema = Schema::from_json_schema(json_schema).unwrap();
+
+    // Sorted key order: "age" before "name".
+    assert!(!schema.fields[0].options.as_ref().unwrap().not_null);
+    assert_eq!(
+        schema.fields[0].options.as_ref().unwrap().default,
+        Some("18".to_string())
+    );
+    assert!(schema.fields[1].options.as_ref().unwrap().not_null);
+}
+
+#[test]
+fn test_from_json_schema_unique_markers() {
+    let json_schema = r#"{
+        "type": "object",
+        "properties": {
+            "email": {"type": "string", "unique": true},
+            "tags": {"type": "array", "uniqueItems": true}
+        }
+    }"#;
+
+    let schema = Schema::from_json_schema(json_schema).unwrap();
+
+    assert!(schema.fields[0].options.as_ref().unwrap().unique);
+    assert!(schema.fields[1].options.as_ref().unwrap().unique);
+}
+
+#[test]
+fn test_from_json_schema_rejects_non_object() {
+    let json_schema = r#"{"type": "string"}"#;
+
+    assert_eq!(
+        Schema::from_json_schema(json_schema).unwrap_err(),
+        JsonSchemaError::NotAnObjectSchema
+    );
+}
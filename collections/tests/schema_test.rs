@@ -1,3 +1,4 @@
+use collections::dialect::SqlDialect;
 use collections::field::{FieldOptions, FieldType};
 use collections::schema::{Schema, SchemaBuilder};
 
@@ -88,6 +89,404 @@ fn test_to_sql_with_options() {
     assert_eq!(schema.to_sql(), sql_expected);
 }
 
+#[test]
+fn test_to_sql_with_foreign_key() {
+    let schema = SchemaBuilder::new()
+        .with_table_name("posts")
+        .with_field(
+            "user_id",
+            FieldType::UUID,
+            Some(FieldOptions::default().with_reference("users", "id")),
+        )
+        .build();
+
+    let sql_expected = "CREATE TABLE posts (id UUID PRIMARY KEY DEFAULT gen_random_uuid(), inserted_at TIMESTAMP without time zone NOT NULL, updated_at TIMESTAMP without time zone NOT NULL, user_id UUID, CONSTRAINT posts_user_id_fkey FOREIGN KEY (user_id) REFERENCES users(id)); CREATE INDEX posts_user_id_idx ON posts(user_id);";
+
+    assert_eq!(schema.to_sql(), sql_expected);
+}
+
+#[test]
+fn test_to_sql_unique_foreign_key_has_no_extra_index() {
+    let schema = SchemaBuilder::new()
+        .with_table_name("profiles")
+        .with_field(
+            "user_id",
+            FieldType::UUID,
+            Some(
+                FieldOptions::new(true, true, None).with_reference("users", "id"),
+            ),
+        )
+        .build();
+
+    let sql_expected = "CREATE TABLE profiles (id UUID PRIMARY KEY DEFAULT gen_random_uuid(), inserted_at TIMESTAMP without time zone NOT NULL, updated_at TIMESTAMP without time zone NOT NULL, user_id UUID NOT NULL, CONSTRAINT profiles_user_id_key UNIQUE (user_id), CONSTRAINT profiles_user_id_fkey FOREIGN KEY (user_id) REFERENCES users(id));";
+
+    assert_eq!(schema.to_sql(), sql_expected);
+}
+
+#[test]
+fn test_to_sql_with_jsonb_path_indexes() {
+    let schema = SchemaBuilder::new()
+        .with_table_name("users")
+        .with_field(
+            "profile",
+            FieldType::Jsonb,
+            Some(
+                FieldOptions::default()
+                    .with_jsonb_path("profile->'address'->>'city'")
+                    .with_jsonb_path("profile->>'status'"),
+            ),
+        )
+        .build();
+
+    let sql_expected = "CREATE TABLE users (id UUID PRIMARY KEY DEFAULT gen_random_uuid(), inserted_at TIMESTAMP without time zone NOT NULL, updated_at TIMESTAMP without time zone NOT NULL, profile JSONB); CREATE INDEX users_profile_address_city_idx ON users USING GIN ((profile->'address'->>'city')); CREATE INDEX users_profile_status_idx ON users USING GIN ((profile->>'status'));";
+
+    assert_eq!(schema.to_sql(), sql_expected);
+}
+
+#[test]
+fn test_to_sql_for_mysql_jsonb_paths_emit_no_gin_index() {
+    let schema = SchemaBuilder::new()
+        .with_table_name("users")
+        .with_field(
+            "profile",
+            FieldType::Jsonb,
+            Some(FieldOptions::default().with_jsonb_path("profile->>'status'")),
+        )
+        .build();
+
+    let sql_expected = "CREATE TABLE `users` (`id` CHAR(36) PRIMARY KEY DEFAULT (UUID()), `inserted_at` DATETIME NOT NULL, `updated_at` DATETIME NOT NULL, `profile` JSON);";
+
+    assert_eq!(schema.to_sql_for(SqlDialect::MySql), sql_expected);
+}
+
+#[test]
+fn test_to_sql_for_sqlite_jsonb_paths_emit_no_gin_index() {
+    let schema = SchemaBuilder::new()
+        .with_table_name("users")
+        .with_field(
+            "profile",
+            FieldType::Jsonb,
+            Some(FieldOptions::default().with_jsonb_path("profile->>'status'")),
+        )
+        .build();
+
+    let sql_expected = "CREATE TABLE users (id TEXT PRIMARY KEY, inserted_at TIMESTAMP NOT NULL, updated_at TIMESTAMP NOT NULL, profile TEXT);";
+
+    assert_eq!(schema.to_sql_for(SqlDialect::Sqlite), sql_expected);
+}
+
+#[test]
+fn test_to_sql_jsonb_without_paths_has_no_index() {
+    let schema = SchemaBuilder::new()
+        .with_table_name("users")
+        .with_field("profile", FieldType::Jsonb, None)
+        .build();
+
+    let sql_expected = "CREATE TABLE users (id UUID PRIMARY KEY DEFAULT gen_random_uuid(), inserted_at TIMESTAMP without time zone NOT NULL, updated_at TIMESTAMP without time zone NOT NULL, profile JSONB);";
+
+    assert_eq!(schema.to_sql(), sql_expected);
+}
+
+#[test]
+fn test_to_sql_for_mysql() {
+    let schema = SchemaBuilder::new()
+        .with_table_name("users")
+        .with_field("name", FieldType::Char, None)
+        .with_field("age", FieldType::Integer, None)
+        .build();
+
+    let sql_expected = "CREATE TABLE `users` (`id` CHAR(36) PRIMARY KEY DEFAULT (UUID()), `inserted_at` DATETIME NOT NULL, `updated_at` DATETIME NOT NULL, `name` VARCHAR(255), `age` BIGINT);";
+
+    assert_eq!(schema.to_sql_for(SqlDialect::MySql), sql_expected);
+}
+
+#[test]
+fn test_to_sql_for_sqlite() {
+    let schema = SchemaBuilder::new()
+        .with_table_name("users")
+        .with_field("name", FieldType::Char, None)
+        .with_field("active", FieldType::Boolean, None)
+        .build();
+
+    let sql_expected = "CREATE TABLE users (id TEXT PRIMARY KEY, inserted_at TIMESTAMP NOT NULL, updated_at TIMESTAMP NOT NULL, name TEXT, active INTEGER);";
+
+    assert_eq!(schema.to_sql_for(SqlDialect::Sqlite), sql_expected);
+}
+
+#[test]
+fn test_to_sql_defaults_to_postgres() {
+    let schema = SchemaBuilder::new()
+        .with_table_name("users")
+        .with_field("name", FieldType::Char, None)
+        .build();
+
+    assert_eq!(schema.to_sql(), schema.to_sql_for(SqlDialect::Postgres));
+}
+
+#[test]
+fn test_diff_add_and_drop_column() {
+    let previous = SchemaBuilder::new()
+        .with_table_name("users")
+        .with_field("name", FieldType::Char, None)
+        .build();
+
+    let current = SchemaBuilder::new()
+        .with_table_name("users")
+        .with_field("email", FieldType::Char, None)
+        .build();
+
+    let statements = current.diff(&previous);
+
+    assert_eq!(
+        statements,
+        vec![
+            "ALTER TABLE users DROP COLUMN name;".to_string(),
+            "ALTER TABLE users ADD COLUMN email VARCHAR(255);".to_string(),
+        ]
+    );
+}
+
+#[test]
+fn test_diff_ignores_system_fields() {
+    let previous = SchemaBuilder::new().with_table_name("users").build();
+    let current = SchemaBuilder::new().with_table_name("users").build();
+
+    assert_eq!(current.diff(&previous), Vec::<String>::new());
+}
+
+#[test]
+fn test_diff_type_and_not_null_change() {
+    let previous = SchemaBuilder::new()
+        .with_table_name("users")
+        .with_field("age", FieldType::Char, None)
+        .build();
+
+    let current = SchemaBuilder::new()
+        .with_table_name("users")
+        .with_field(
+            "age",
+            FieldType::Integer,
+            Some(FieldOptions::new(false, true, None)),
+        )
+        .build();
+
+    let statements = current.diff(&previous);
+
+    assert_eq!(
+        statements,
+        vec![
+            "ALTER TABLE users ALTER COLUMN age TYPE BIGINT;".to_string(),
+            "ALTER TABLE users ALTER COLUMN age SET NOT NULL;".to_string(),
+        ]
+    );
+}
+
+#[test]
+fn test_diff_default_value_change() {
+    let previous = SchemaBuilder::new()
+        .with_table_name("users")
+        .with_field(
+            "age",
+            FieldType::Integer,
+            Some(FieldOptions::new(false, false, Some("1".to_string()))),
+        )
+        .build();
+
+    let current = SchemaBuilder::new()
+        .with_table_name("users")
+        .with_field("age", FieldType::Integer, None)
+        .build();
+
+    assert_eq!(
+        current.diff(&previous),
+        vec!["ALTER TABLE users ALTER COLUMN age DROP DEFAULT;".to_string()]
+    );
+}
+
+#[test]
+fn test_diff_foreign_key_added() {
+    let previous = SchemaBuilder::new()
+        .with_table_name("posts")
+        .with_field("user_id", FieldType::UUID, None)
+        .build();
+
+    let current = SchemaBuilder::new()
+        .with_table_name("posts")
+        .with_field(
+            "user_id",
+            FieldType::UUID,
+            Some(FieldOptions::default().with_reference("users", "id")),
+        )
+        .build();
+
+    assert_eq!(
+        current.diff(&previous),
+        vec![
+            "ALTER TABLE posts ADD CONSTRAINT posts_user_id_fkey FOREIGN KEY (user_id) REFERENCES users(id);"
+                .to_string()
+        ]
+    );
+}
+
+#[test]
+fn test_diff_new_column_with_foreign_key_adds_constraint() {
+    let previous = SchemaBuilder::new().with_table_name("posts").build();
+
+    let current = SchemaBuilder::new()
+        .with_table_name("posts")
+        .with_field(
+            "user_id",
+            FieldType::UUID,
+            Some(FieldOptions::default().with_reference("users", "id")),
+        )
+        .build();
+
+    let statements = current.diff(&previous);
+
+    assert_eq!(
+        statements,
+        vec![
+            "ALTER TABLE posts ADD COLUMN user_id UUID;".to_string(),
+            "ALTER TABLE posts ADD CONSTRAINT posts_user_id_fkey FOREIGN KEY (user_id) REFERENCES users(id);"
+                .to_string(),
+        ]
+    );
+}
+
+#[test]
+fn test_diff_new_column_with_unique_adds_constraint() {
+    let previous = SchemaBuilder::new().with_table_name("users").build();
+
+    let current = SchemaBuilder::new()
+        .with_table_name("users")
+        .with_field(
+            "email",
+            FieldType::Char,
+            Some(FieldOptions::new(true, false, None)),
+        )
+        .build();
+
+    let statements = current.diff(&previous);
+
+    assert_eq!(
+        statements,
+        vec![
+            "ALTER TABLE users ADD COLUMN email VARCHAR(255);".to_string(),
+            "ALTER TABLE users ADD CONSTRAINT users_email_key UNIQUE (email);".to_string(),
+        ]
+    );
+}
+
+#[test]
+fn test_diff_drops_constraint_before_column() {
+    let previous = SchemaBuilder::new()
+        .with_table_name("posts")
+        .with_field(
+            "user_id",
+            FieldType::UUID,
+            Some(FieldOptions::default().with_reference("users", "id")),
+        )
+        .build();
+
+    let current = SchemaBuilder::new().with_table_name("posts").build();
+
+    assert_eq!(
+        current.diff(&previous),
+        vec![
+            "ALTER TABLE posts DROP CONSTRAINT posts_user_id_fkey;".to_string(),
+            "ALTER TABLE posts DROP COLUMN user_id;".to_string(),
+        ]
+    );
+}
+
+#[test]
+fn test_migration_plan_warns_on_narrowing_text_to_char() {
+    let previous = SchemaBuilder::new()
+        .with_table_name("users")
+        .with_field("bio", FieldType::Text, None)
+        .build();
+
+    let current = SchemaBuilder::new()
+        .with_table_name("users")
+        .with_field("bio", FieldType::Char, None)
+        .build();
+
+    let plan = current.migration_plan(&previous);
+
+    assert_eq!(
+        plan.statements,
+        vec!["ALTER TABLE users ALTER COLUMN bio TYPE VARCHAR(255);".to_string()]
+    );
+    assert_eq!(plan.warnings.len(), 1);
+    assert!(plan.warnings[0].contains("bio"));
+}
+
+#[test]
+fn test_migration_plan_warns_on_not_null_without_default() {
+    let previous = SchemaBuilder::new()
+        .with_table_name("users")
+        .with_field("name", FieldType::Char, None)
+        .build();
+
+    let current = SchemaBuilder::new()
+        .with_table_name("users")
+        .with_field(
+            "name",
+            FieldType::Char,
+            Some(FieldOptions::new(false, true, None)),
+        )
+        .build();
+
+    let plan = current.migration_plan(&previous);
+
+    assert_eq!(plan.warnings.len(), 1);
+    assert!(plan.warnings[0].contains("NOT NULL"));
+}
+
+#[test]
+fn test_migration_plan_no_warning_when_not_null_has_default() {
+    let previous = SchemaBuilder::new()
+        .with_table_name("users")
+        .with_field("name", FieldType::Char, None)
+        .build();
+
+    let current = SchemaBuilder::new()
+        .with_table_name("users")
+        .with_field(
+            "name",
+            FieldType::Char,
+            Some(FieldOptions::new(false, true, Some("unknown".to_string()))),
+        )
+        .build();
+
+    assert!(current.migration_plan(&previous).warnings.is_empty());
+}
+
+#[test]
+fn test_migration_plan_new_column_with_foreign_key_adds_constraint() {
+    let previous = SchemaBuilder::new().with_table_name("posts").build();
+
+    let current = SchemaBuilder::new()
+        .with_table_name("posts")
+        .with_field(
+            "user_id",
+            FieldType::UUID,
+            Some(FieldOptions::default().with_reference("users", "id")),
+        )
+        .build();
+
+    let plan = current.migration_plan(&previous);
+
+    assert_eq!(
+        plan.statements,
+        vec![
+            "ALTER TABLE posts ADD COLUMN user_id UUID;".to_string(),
+            "ALTER TABLE posts ADD CONSTRAINT posts_user_id_fkey FOREIGN KEY (user_id) REFERENCES users(id);"
+                .to_string(),
+        ]
+    );
+}
+
 #[test]
 fn test_schema_builder_new() {
     let schema = SchemaBuilder::new().build();
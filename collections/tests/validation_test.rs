@@ -0,0 +1,99 @@
+use collections::field::{Field, FieldOptions, FieldType};
+use serde_json::json;
+
+#[test]
+fn test_validate_integer() {
+    let field = Field::new("age", FieldType::Integer, None);
+
+    assert!(field.validate(&json!(30)).is_ok());
+    assert!(field.validate(&json!(-5)).is_ok());
+    assert!(field.validate(&json!(18446744073709551615u64)).is_err());
+    assert!(field.validate(&json!(1.5)).is_err());
+    assert!(field.validate(&json!("30")).is_err());
+}
+
+#[test]
+fn test_validate_double_accepts_any_number() {
+    let field = Field::new("balance", FieldType::Double, None);
+
+    assert!(field.validate(&json!(1.5)).is_ok());
+    assert!(field.validate(&json!(5)).is_ok());
+    assert!(field.validate(&json!("1.5")).is_err());
+}
+
+#[test]
+fn test_validate_uuid() {
+    let field = Field::new("external_id", FieldType::UUID, None);
+
+    assert!(field.validate(&json!("550e8400-e29b-41d4-a716-446655440000")).is_ok());
+    assert!(field.validate(&json!("not-a-uuid")).is_err());
+}
+
+#[test]
+fn test_validate_timestamp_date_and_time() {
+    let timestamp = Field::new("created_at", FieldType::Timestamp, None);
+    let date = Field::new("birthday", FieldType::Date, None);
+    let time = Field::new("reminder", FieldType::Time, None);
+
+    assert!(timestamp.validate(&json!("2024-01-02T03:04:05Z")).is_ok());
+    assert!(timestamp.validate(&json!("not a timestamp")).is_err());
+
+    assert!(date.validate(&json!("1990-05-01")).is_ok());
+    assert!(date.validate(&json!("1990/05/01")).is_err());
+
+    assert!(time.validate(&json!("03:04:05")).is_ok());
+    assert!(time.validate(&json!("not a time")).is_err());
+}
+
+#[test]
+fn test_validate_char_enforces_length_limit() {
+    let field = Field::new("name", FieldType::Char, None);
+
+    assert!(field.validate(&json!("a".repeat(255))).is_ok());
+    assert!(field.validate(&json!("a".repeat(256))).is_err());
+}
+
+#[test]
+fn test_validate_json_accepts_objects_and_arrays() {
+    let field = Field::new("metadata", FieldType::Json, None);
+
+    assert!(field.validate(&json!({"a": 1})).is_ok());
+    assert!(field.validate(&json!([1, 2, 3])).is_ok());
+    assert!(field.validate(&json!("not json")).is_err());
+}
+
+#[test]
+fn test_validate_char_counts_characters_not_bytes() {
+    let field = Field::new("name", FieldType::Char, None);
+
+    assert!(field.validate(&json!("é".repeat(255))).is_ok());
+    assert!(field.validate(&json!("é".repeat(256))).is_err());
+}
+
+#[test]
+fn test_validate_jsonb_accepts_objects_and_arrays() {
+    let field = Field::new("profile", FieldType::Jsonb, None);
+
+    assert!(field.validate(&json!({"theme": "dark"})).is_ok());
+    assert!(field.validate(&json!([1, 2, 3])).is_ok());
+    assert!(field.validate(&json!("not json")).is_err());
+}
+
+#[test]
+fn test_validate_not_null() {
+    let field = Field::new(
+        "name",
+        FieldType::Char,
+        Some(FieldOptions::new(false, true, None)),
+    );
+
+    assert!(field.validate(&json!(null)).is_err());
+    assert!(field.validate(&json!("Ada")).is_ok());
+}
+
+#[test]
+fn test_validate_nullable_field_allows_null() {
+    let field = Field::new("nickname", FieldType::Char, None);
+
+    assert!(field.validate(&json!(null)).is_ok());
+}
@@ -0,0 +1,174 @@
+//! `#[derive(Table)]` generates a `Schema` for a plain struct so callers
+//! don't have to hand-write `SchemaBuilder` chains.
+//!
+//! Field types are inferred from the Rust type (`i64`/`i32` -> `Integer`,
+//! `f64` -> `Double`, `String` -> `Char`, `bool` -> `Boolean`, `Option<T>`
+//! -> the inner type's mapping with `not_null = false`, anything else ->
+//! `not_null = true`). The table name defaults to the snake_cased struct
+//! name with an `s` appended, and `#[unique]`, `#[not_null]`, and
+//! `#[default = "..."]` field attributes populate `FieldOptions`. Fields
+//! named `id`, `inserted_at`, or `updated_at` are dropped, matching
+//! `Schema::add_field`'s system-field handling.
+
+use proc_macro::TokenStream;
+use quote::quote;
+use syn::{parse_macro_input, Data, DeriveInput, Fields, GenericArgument, PathArguments, Type};
+
+const SYSTEM_FIELD_NAMES: [&str; 3] = ["id", "inserted_at", "updated_at"];
+
+#[proc_macro_derive(Table, attributes(unique, not_null, default))]
+pub fn derive_table(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    let struct_name = &input.ident;
+    let table_name = table_name(&struct_name.to_string());
+
+    let fields = match &input.data {
+        Data::Struct(data) => match &data.fields {
+            Fields::Named(fields) => &fields.named,
+            _ => panic!("#[derive(Table)] only supports structs with named fields"),
+        },
+        _ => panic!("#[derive(Table)] only supports structs"),
+    };
+
+    let field_calls = fields
+        .iter()
+        .filter_map(|field| {
+            let name = field.ident.as_ref().unwrap().to_string();
+
+            if SYSTEM_FIELD_NAMES.contains(&name.as_str()) {
+                return None;
+            }
+
+            let (field_type, optional) = field_type_for(&field.ty);
+            let unique = has_attr(field, "unique");
+            let not_null = !optional || has_attr(field, "not_null");
+            let default = default_attr(field);
+
+            let default_tokens = match default {
+                Some(value) => quote! { Some(#value.to_string()) },
+                None => quote! { None },
+            };
+
+            Some(quote! {
+                schema.add_field(
+                    #name,
+                    #field_type,
+                    Some(collections::field::FieldOptions::new(#unique, #not_null, #default_tokens)),
+                );
+            })
+        })
+        .collect::<Vec<_>>();
+
+    let expanded = quote! {
+        impl #struct_name {
+            fn schema() -> collections::schema::Schema {
+                let mut schema = collections::schema::Schema::new();
+                schema.table_name = #table_name.to_string();
+
+                #(#field_calls)*
+
+                schema
+            }
+        }
+    };
+
+    expanded.into()
+}
+
+fn table_name(struct_name: &str) -> String {
+    format!("{}s", to_snake_case(struct_name))
+}
+
+fn to_snake_case(name: &str) -> String {
+    let mut snake = String::new();
+
+    for (index, ch) in name.char_indices() {
+        if ch.is_uppercase() {
+            if index > 0 {
+                snake.push('_');
+            }
+            snake.extend(ch.to_lowercase());
+        } else {
+            snake.push(ch);
+        }
+    }
+
+    snake
+}
+
+/// Map a Rust field type onto a `FieldType` expression, unwrapping `Option<T>`
+/// and reporting whether the field was optional.
+fn field_type_for(ty: &Type) -> (proc_macro2::TokenStream, bool) {
+    if let Some(inner) = option_inner_type(ty) {
+        let (field_type, _) = field_type_for(inner);
+        return (field_type, true);
+    }
+
+    let field_type = match type_name(ty).as_str() {
+        "i64" | "i32" => quote! { collections::field::FieldType::Integer },
+        "f64" => quote! { collections::field::FieldType::Double },
+        "String" => quote! { collections::field::FieldType::Char },
+        "bool" => quote! { collections::field::FieldType::Boolean },
+        other => panic!("#[derive(Table)] does not support field type `{}`", other),
+    };
+
+    (field_type, false)
+}
+
+fn option_inner_type(ty: &Type) -> Option<&Type> {
+    let Type::Path(path) = ty else {
+        return None;
+    };
+
+    let segment = path.path.segments.last()?;
+
+    if segment.ident != "Option" {
+        return None;
+    }
+
+    let PathArguments::AngleBracketed(args) = &segment.arguments else {
+        return None;
+    };
+
+    args.args.iter().find_map(|arg| match arg {
+        GenericArgument::Type(ty) => Some(ty),
+        _ => None,
+    })
+}
+
+fn type_name(ty: &Type) -> String {
+    match ty {
+        Type::Path(path) => path
+            .path
+            .segments
+            .last()
+            .map(|segment| segment.ident.to_string())
+            .unwrap_or_default(),
+        _ => String::new(),
+    }
+}
+
+fn has_attr(field: &syn::Field, name: &str) -> bool {
+    field.attrs.iter().any(|attr| attr.path().is_ident(name))
+}
+
+fn default_attr(field: &syn::Field) -> Option<String> {
+    field.attrs.iter().find_map(|attr| {
+        if !attr.path().is_ident("default") {
+            return None;
+        }
+
+        let syn::Meta::NameValue(meta) = &attr.meta else {
+            return None;
+        };
+
+        let syn::Expr::Lit(expr_lit) = &meta.value else {
+            return None;
+        };
+
+        match &expr_lit.lit {
+            syn::Lit::Str(lit) => Some(lit.value()),
+            _ => None,
+        }
+    })
+}
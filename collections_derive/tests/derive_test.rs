@@ -0,0 +1,51 @@
+use collections::field::FieldType;
+use collections_derive::Table;
+
+#[derive(Table)]
+struct User {
+    id: i64,
+    name: String,
+    #[unique]
+    email: String,
+    age: Option<i32>,
+    #[default = "true"]
+    is_active: bool,
+    inserted_at: String,
+    updated_at: String,
+}
+
+#[test]
+fn test_table_name_defaults_to_snake_case_plural() {
+    let schema = User::schema();
+
+    assert_eq!(schema.table_name, "users");
+}
+
+#[test]
+fn test_table_drops_system_fields() {
+    let schema = User::schema();
+
+    assert_eq!(schema.fields.len(), 4);
+    assert_eq!(schema.fields[0].name, "name");
+    assert_eq!(schema.fields[1].name, "email");
+    assert_eq!(schema.fields[2].name, "age");
+    assert_eq!(schema.fields[3].name, "is_active");
+    assert_eq!(schema.fields[3].type_, FieldType::Boolean);
+    assert_eq!(
+        schema.fields[3].options.as_ref().unwrap().default,
+        Some("true".to_string())
+    );
+}
+
+#[test]
+fn test_table_infers_types_and_nullability() {
+    let schema = User::schema();
+
+    assert_eq!(schema.fields[0].type_, FieldType::Char);
+    assert!(schema.fields[0].options.as_ref().unwrap().not_null);
+
+    assert!(schema.fields[1].options.as_ref().unwrap().unique);
+
+    assert_eq!(schema.fields[2].type_, FieldType::Integer);
+    assert!(!schema.fields[2].options.as_ref().unwrap().not_null);
+}